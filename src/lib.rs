@@ -1,33 +1,104 @@
+use std::ops::{Bound, Range, RangeBounds};
+
 use thiserror::Error;
 
 pub type Time = i64;
 
+/// A discrete, totally ordered value with an immediate predecessor and successor. This is what
+/// lets `TimeIntervals` decide whether two intervals are adjacent (e.g. `[1, 5]` and `[6, 10]`
+/// merge into `[1, 10]`) for any steppable `T`, not just `i64`.
+///
+/// Implemented for all of Rust's integer primitives (so e.g. `u32` indices work out of the box);
+/// implement it for your own discrete, monotonically-steppable key (e.g. a newtype wrapping a
+/// `Duration` tick count) to use it with `TimeInterval`/`TimeIntervals` directly.
+pub trait Step: Ord + Copy {
+    /// The value immediately before `self`.
+    fn prev(self) -> Self;
+
+    /// The value immediately after `self`.
+    fn next(self) -> Self;
+
+    /// Like `next`, but returns `None` instead of overflowing when `self` is already the largest
+    /// representable value, for call sites that can't otherwise rule that case out.
+    fn checked_next(self) -> Option<Self>;
+}
+
+macro_rules! impl_step_for_integers {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Step for $t {
+                fn prev(self) -> Self {
+                    self - 1
+                }
+
+                fn next(self) -> Self {
+                    self + 1
+                }
+
+                fn checked_next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_step_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct TimeInterval {
-    start: Time,
-    end: Time,
+pub struct TimeInterval<T = Time> {
+    start: T,
+    end: T,
 }
 
-impl TimeInterval {
-    pub fn new(start: Time, end: Time) -> Result<Self, TimeIntervalError> {
+impl<T: Step> TimeInterval<T> {
+    pub fn new(start: T, end: T) -> Result<Self, TimeIntervalError> {
         if start > end {
             Err(TimeIntervalError)
         } else {
             Ok(Self { start, end })
         }
     }
+
+    /// Builds a `TimeInterval` from any standard `RangeBounds`, such as `a..b`, `a..=b`, so
+    /// callers can express the common half-open `[start, end)` convention without manually
+    /// subtracting one from the end themselves. An exclusive upper bound is normalized to our
+    /// inclusive internal representation.
+    ///
+    /// `TimeInterval` is always a finite, closed range, so an unbounded side (e.g. `..b` or `a..`)
+    /// or an empty/degenerate range (e.g. `5..5`) is rejected with a `TimeIntervalError`.
+    pub fn from_range<R: RangeBounds<T>>(range: R) -> Result<Self, TimeIntervalError> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_next().ok_or(TimeIntervalError)?,
+            Bound::Unbounded => return Err(TimeIntervalError),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end,
+            Bound::Excluded(&end) => {
+                if end <= start {
+                    return Err(TimeIntervalError);
+                }
+                end.prev()
+            }
+            Bound::Unbounded => return Err(TimeIntervalError),
+        };
+
+        Self::new(start, end)
+    }
 }
 
-impl PartialEq<(Time, Time)> for TimeInterval {
-    fn eq(&self, &(start, end): &(Time, Time)) -> bool {
+impl<T: Step> PartialEq<(T, T)> for TimeInterval<T> {
+    fn eq(&self, &(start, end): &(T, T)) -> bool {
         self.start == start && self.end == end
     }
 }
 
-impl TryFrom<(Time, Time)> for TimeInterval {
+impl<T: Step> TryFrom<(T, T)> for TimeInterval<T> {
     type Error = TimeIntervalError;
 
-    fn try_from((start, end): (Time, Time)) -> Result<Self, Self::Error> {
+    fn try_from((start, end): (T, T)) -> Result<Self, Self::Error> {
         Self::new(start, end)
     }
 }
@@ -37,7 +108,7 @@ impl TryFrom<(Time, Time)> for TimeInterval {
 pub struct TimeIntervalError;
 
 #[derive(Clone, Debug)]
-pub struct TimeIntervals {
+pub struct TimeIntervals<T = Time> {
     // When we construct a TimeIntervals structure, we sort the intervals by their start time and
     // keep them in a Vec as contiguously allocated memory to optimize memory access and lower
     // initialization time (fewer allocations and pointer indirection). We're optimizing for the
@@ -46,11 +117,11 @@ pub struct TimeIntervals {
     // intervals to the set, we could consider a more complex data structure like a binary tree or a
     // B+-tree with a high order to optimize the cost of mutating intervals in our index while
     // maintaining performant logarithmic search capability.
-    intervals: Vec<TimeInterval>,
+    intervals: Vec<TimeInterval<T>>,
 }
 
-impl TimeIntervals {
-    pub fn new(mut intervals: Vec<TimeInterval>) -> Self {
+impl<T: Step> TimeIntervals<T> {
+    pub fn new(mut intervals: Vec<TimeInterval<T>>) -> Self {
         // Because TimeInterval is correct by construction and cannot be constructed with an invalid
         // start & end time via its public API, we do not have to do any additional validation on
         // the list of intervals before sorting them and constructing ourself.
@@ -68,25 +139,10 @@ impl TimeIntervals {
         //
         // So to do this, we iterate through the sorted intervals and combine any interval that
         // overlaps or is adjacent with the previous interval. Prime case for a reduction!
-        intervals = intervals.into_iter().fold(Vec::new(), |mut intervals, interval| {
-            // If the last interval in our Vec overlaps or is adjacent with the current interval, we merge them.
-            if let Some(last) = intervals.last_mut() {
-                if last.end >= interval.start - 1 {
-                    last.end = last.end.max(interval.end);
-                } else {
-                    intervals.push(interval);
-                }
-            } else {
-                intervals.push(interval);
-            }
-
-            intervals
-        });
-
-        Self { intervals }
+        Self { intervals: coalesce(intervals) }
     }
 
-    pub fn contains_time(&self, time: Time) -> bool {
+    pub fn contains_time(&self, time: T) -> bool {
         // If there are no intervals, don't bother doing the binary search. This guarantees that the
         // returned index from partition_point is within the bounds of the Vec.
         if self.intervals.is_empty() {
@@ -109,27 +165,391 @@ impl TimeIntervals {
     pub fn is_empty(&self) -> bool {
         self.intervals.is_empty()
     }
+
+    /// Checks whether `time` falls within one of our intervals, the same as `contains_time`, but
+    /// amortizes to O(1) per call when `time` is queried in non-decreasing order by reusing the
+    /// supplied `cursor` instead of re-running a binary search from scratch every time.
+    ///
+    /// This is intended for callers scanning a long, already-sorted stream of timestamps (e.g.
+    /// walking events in time order) where `contains_time`'s fresh `partition_point` on every call
+    /// is wasted work. If `time` ever regresses below the previously queried time, we transparently
+    /// fall back to the binary search and re-anchor the cursor there, so `seek` is always correct,
+    /// just not always O(1). Note this is deliberately *not* comparing `time` against the start of
+    /// the interval the cursor currently sits on: the cursor is routinely parked ahead of `time` in
+    /// a gap between intervals, and that's normal forward progress, not a regression.
+    pub fn seek(&self, time: T, cursor: &mut Cursor<T>) -> bool {
+        let regressed = match cursor.last_time {
+            Some(last_time) => time < last_time,
+            None => false,
+        };
+
+        if regressed || cursor.index >= self.intervals.len() {
+            let index = self.intervals.partition_point(|interval| interval.start <= time);
+            cursor.index = index.saturating_sub(1);
+        }
+
+        while cursor.index < self.intervals.len() && self.intervals[cursor.index].end < time {
+            cursor.index += 1;
+        }
+
+        cursor.last_time = Some(time);
+
+        cursor.index < self.intervals.len() && self.intervals[cursor.index].start <= time
+    }
+
+    /// Returns whether every point covered by `other` is also covered by `self`.
+    ///
+    /// Because both structs already store sorted, merged, strictly-separated intervals, we can
+    /// decide this in O(N+M) rather than naively comparing every pair of intervals: walk a cursor
+    /// `i` over `self.intervals` and, for each interval in `other`, advance `i` while it ends
+    /// before that interval starts, then require that `self[i]` fully contains it.
+    pub fn is_superset(&self, other: &TimeIntervals<T>) -> bool {
+        if other.intervals.is_empty() {
+            return true;
+        }
+
+        if self.intervals.is_empty() {
+            return false;
+        }
+
+        let mut i = 0;
+        for other_interval in &other.intervals {
+            while i < self.intervals.len() && self.intervals[i].end < other_interval.start {
+                i += 1;
+            }
+
+            let contained = i < self.intervals.len()
+                && self.intervals[i].start <= other_interval.start
+                && self.intervals[i].end >= other_interval.end;
+
+            if !contained {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns whether every point covered by `self` is also covered by `other`. This is simply
+    /// `is_superset` with the operands swapped.
+    pub fn is_subset(&self, other: &TimeIntervals<T>) -> bool {
+        other.is_superset(self)
+    }
+
+    /// Returns a new `TimeIntervals` covering every point covered by either `self` or `other`.
+    ///
+    /// Both operands are already sorted and merged, so rather than concatenating the two Vecs and
+    /// paying for another full sort, we merge them in a single O(N+M) linear pass (like the merge
+    /// step of merge sort) and then coalesce the result the same way `new` does.
+    pub fn union(&self, other: &TimeIntervals<T>) -> TimeIntervals<T> {
+        let mut i = 0;
+        let mut j = 0;
+        let mut merged = Vec::with_capacity(self.intervals.len() + other.intervals.len());
+
+        while i < self.intervals.len() && j < other.intervals.len() {
+            if self.intervals[i].start <= other.intervals[j].start {
+                merged.push(self.intervals[i]);
+                i += 1;
+            } else {
+                merged.push(other.intervals[j]);
+                j += 1;
+            }
+        }
+
+        merged.extend(&self.intervals[i..]);
+        merged.extend(&other.intervals[j..]);
+
+        Self { intervals: coalesce(merged) }
+    }
+
+    /// Returns a new `TimeIntervals` covering only the points covered by both `self` and `other`.
+    ///
+    /// Both operands are sorted and merged, so we can advance two pointers across them, emitting
+    /// `[max(a.start, b.start), min(a.end, b.end)]` whenever that range is non-empty and then
+    /// advancing whichever interval ends first, in a single O(N+M) pass.
+    pub fn intersection(&self, other: &TimeIntervals<T>) -> TimeIntervals<T> {
+        let mut i = 0;
+        let mut j = 0;
+        let mut intervals = Vec::new();
+
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let a = self.intervals[i];
+            let b = other.intervals[j];
+
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+
+            if start <= end {
+                intervals.push(TimeInterval { start, end });
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals: coalesce(intervals) }
+    }
+
+    /// Returns a new `TimeIntervals` covering the points covered by `self` but not by `other`
+    /// (i.e. `self` \ `other`).
+    ///
+    /// We walk `self`'s intervals in order, and for each one subtract any overlapping `other`
+    /// intervals, splitting it into as many pieces as needed to carve out the overlaps (an `other`
+    /// interval landing strictly inside splits it into two). A pointer into `other` is only ever
+    /// advanced past intervals that have ended before the current `self` interval starts, since
+    /// both Vecs are sorted and such an interval can never overlap a later one either.
+    pub fn difference(&self, other: &TimeIntervals<T>) -> TimeIntervals<T> {
+        let mut j = 0;
+        let mut intervals = Vec::new();
+
+        for &interval in &self.intervals {
+            while j < other.intervals.len() && other.intervals[j].end < interval.start {
+                j += 1;
+            }
+
+            let mut start = interval.start;
+            let mut k = j;
+            let mut consumed = false;
+
+            while k < other.intervals.len() && other.intervals[k].start <= interval.end {
+                let cut = other.intervals[k];
+
+                if cut.start > start {
+                    intervals.push(TimeInterval {
+                        start,
+                        end: cut.start.prev(),
+                    });
+                }
+
+                // A cut reaching all the way to (or past) `interval.end` consumes the rest of the
+                // interval outright, so we're done with it — and we must stop here rather than call
+                // `cut.end.next()`, which would overflow whenever `cut.end` is `T::MAX`. Only once we
+                // know `cut.end < interval.end` is stepping past it guaranteed not to overflow.
+                if cut.end >= interval.end {
+                    consumed = true;
+                    break;
+                }
+
+                start = start.max(cut.end.next());
+                k += 1;
+            }
+
+            if !consumed && start <= interval.end {
+                intervals.push(TimeInterval {
+                    start,
+                    end: interval.end,
+                });
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// Returns an iterator over the maximal uncovered sub-intervals of `domain` — i.e. the
+    /// complement of our covered set, clamped to `[domain.start, domain.end]`. This is the dual of
+    /// `contains_time`: it answers "when was nothing scheduled?" within a bounding window.
+    ///
+    /// Because our intervals are already sorted and coalesced, this walks them once in order,
+    /// yielding the gap between the previously covered point (starting at `domain.start`) and each
+    /// interval's start when positive, plus a final trailing gap up to `domain.end`.
+    pub fn gaps(&self, domain: TimeInterval<T>) -> impl Iterator<Item = TimeInterval<T>> + '_ {
+        let mut cursor = domain.start;
+        let mut index = self.intervals.partition_point(|interval| interval.end < domain.start);
+        let mut done = false;
+
+        std::iter::from_fn(move || {
+            while !done {
+                let no_more_coverage = match self.intervals.get(index) {
+                    Some(interval) => interval.start > domain.end,
+                    None => true,
+                };
+
+                if no_more_coverage {
+                    done = true;
+                    if cursor <= domain.end {
+                        return Some(TimeInterval {
+                            start: cursor,
+                            end: domain.end,
+                        });
+                    }
+                    return None;
+                }
+
+                let interval = self.intervals[index];
+                index += 1;
+
+                if interval.start > cursor {
+                    let gap = TimeInterval {
+                        start: cursor,
+                        end: interval.start.prev(),
+                    };
+
+                    // An interval reaching `domain.end` (or past it) covers everything left in the
+                    // domain, so there's no later gap to find — and we must not call `.next()` on
+                    // `interval.end` in that case, since it overflows whenever `interval.end` is
+                    // `T::MAX`. We only step past it once we know `interval.end < domain.end`.
+                    if interval.end >= domain.end {
+                        done = true;
+                    } else {
+                        cursor = interval.end.next();
+                    }
+
+                    return Some(gap);
+                }
+
+                if interval.end >= domain.end {
+                    done = true;
+                } else {
+                    cursor = cursor.max(interval.end.next());
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Reports whether any part of `range` is covered by our intervals. `range` is accepted as any
+    /// standard `RangeBounds` (e.g. a half-open `a..b`), making it the `RangeBounds` analog of
+    /// `contains_time` for querying a span instead of a single point.
+    ///
+    /// This runs the same binary search as `contains_time`, just against `range`'s end instead of a
+    /// single time: an interval fails to overlap `range` iff its start is after `range`'s end or its
+    /// end is before `range`'s start, so we binary search for the last interval whose start is
+    /// `<= range.end` and check whether its end also reaches back to `range.start`.
+    ///
+    /// An unbounded or empty/degenerate `range` can never be covered, since `TimeInterval` can't
+    /// represent one, so this simply returns `false` for those.
+    pub fn overlaps<R: RangeBounds<T>>(&self, range: R) -> bool {
+        let Ok(query) = TimeInterval::from_range(range) else {
+            return false;
+        };
+
+        if self.intervals.is_empty() {
+            return false;
+        }
+
+        let index = self.intervals.partition_point(|interval| interval.start <= query.end);
+        index > 0 && self.intervals[index - 1].end >= query.start
+    }
+}
+
+// `coverage` and `overlap_count` need real arithmetic (subtracting endpoints, summing durations)
+// rather than just the single-step predecessor/successor `Step` provides, so we scope them to the
+// concrete `Time` alias instead of generalizing over every `T: Step`.
+impl TimeIntervals<Time> {
+    /// Returns the half-open `[left, right)` slice index range of `self.intervals` that could
+    /// possibly overlap `query`, via the same binary searches `overlap_count` and `coverage` both
+    /// need: since our intervals are coalesced, they're sorted by end as well as by start, so we
+    /// binary search for the first interval whose start is past `query.end` to bound the right
+    /// side, and the first whose end reaches `query.start` to bound the left side.
+    fn overlap_bounds(&self, query: TimeInterval<Time>) -> Range<usize> {
+        let left = self.intervals.partition_point(|interval| interval.end < query.start);
+        let right = self.intervals.partition_point(|interval| interval.start <= query.end);
+        left..right
+    }
+
+    /// Returns how many of our intervals overlap `query`, in O(log N).
+    ///
+    /// An interval fails to overlap `query` iff its start is after `query`'s end or its end is
+    /// before `query`'s start, so the intervals that do overlap are exactly the contiguous slice
+    /// bounded by `overlap_bounds`. This imports the counting-by-endpoint-search idea from BITS
+    /// (Binary Interval Search) to measure utilization of a query window cheaply.
+    pub fn overlap_count(&self, query: TimeInterval<Time>) -> usize {
+        self.overlap_bounds(query).len()
+    }
+
+    /// Returns the total amount of `query` that is covered by our intervals, in O(log N + K) where
+    /// K is the number of intervals overlapping `query`.
+    ///
+    /// We reuse `overlap_bounds` to bound the slice of intervals that can possibly overlap
+    /// `query` in O(log N), then sum each one's overlap with `query`, clamped to `query` itself,
+    /// which takes one step per interval in that slice.
+    pub fn coverage(&self, query: TimeInterval<Time>) -> Time {
+        let bounds = self.overlap_bounds(query);
+
+        self.intervals[bounds]
+            .iter()
+            .map(|interval| {
+                let start = interval.start.max(query.start);
+                let end = interval.end.min(query.end);
+                end - start + 1
+            })
+            .sum()
+    }
+}
+
+/// Folds a Vec of intervals already sorted by start time into the fewest possible intervals by
+/// combining any that overlap or are perfectly adjacent (the same reduction `new` applies).
+fn coalesce<T: Step>(intervals: Vec<TimeInterval<T>>) -> Vec<TimeInterval<T>> {
+    intervals.into_iter().fold(Vec::new(), |mut intervals, interval| {
+        if let Some(last) = intervals.last_mut() {
+            // Overlapping intervals are merged without stepping anything. Only a genuinely
+            // adjacent pair (`last.end + 1 == interval.start`) needs `.next()`, and by then we
+            // already know `last.end < interval.start`, so `last.end` can't be `T::MAX` (nothing
+            // can start after `T::MAX`) and `.next()` can't overflow. This also sidesteps calling
+            // `.prev()` on `interval.start`, which would underflow for an unsigned `T` whenever an
+            // interval starts at zero.
+            if last.end >= interval.start || last.end.next() == interval.start {
+                last.end = last.end.max(interval.end);
+            } else {
+                intervals.push(interval);
+            }
+        } else {
+            intervals.push(interval);
+        }
+
+        intervals
+    })
+}
+
+/// An opaque cursor used with `TimeIntervals::seek` to amortize repeated, non-decreasing time
+/// queries down to O(1) per call. Cursors are cheap, `Copy`, and independent of one another, so
+/// each thread or query stream should keep its own.
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor<T = Time> {
+    index: usize,
+    last_time: Option<T>,
+}
+
+impl<T> Cursor<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T> Default for Cursor<T> {
+    // Derived `Default` would add a spurious `T: Default` bound (Option<T> is unconditionally
+    // `Default` regardless of `T`), so this is implemented by hand.
+    fn default() -> Self {
+        Self {
+            index: 0,
+            last_time: None,
+        }
+    }
 }
 
 // These are just some of the convenient ways to construct a TimeIntervals structure.
-impl From<Vec<TimeInterval>> for TimeIntervals {
-    fn from(intervals: Vec<TimeInterval>) -> Self {
+impl<T: Step> From<Vec<TimeInterval<T>>> for TimeIntervals<T> {
+    fn from(intervals: Vec<TimeInterval<T>>) -> Self {
         Self::new(intervals)
     }
 }
 
-impl FromIterator<TimeInterval> for TimeIntervals {
-    fn from_iter<T: IntoIterator<Item = TimeInterval>>(iter: T) -> Self {
+impl<T: Step> FromIterator<TimeInterval<T>> for TimeIntervals<T> {
+    fn from_iter<I: IntoIterator<Item = TimeInterval<T>>>(iter: I) -> Self {
         Self::new(iter.into_iter().collect())
     }
 }
 
 // This is a convenient way to attempt to construct a TimeIntervals structure from a slice of time
 // tuples.
-impl TryFrom<&[(Time, Time)]> for TimeIntervals {
+impl<T: Step> TryFrom<&[(T, T)]> for TimeIntervals<T> {
     type Error = TimeIntervalError;
 
-    fn try_from(intervals: &[(Time, Time)]) -> Result<Self, Self::Error> {
+    fn try_from(intervals: &[(T, T)]) -> Result<Self, Self::Error> {
         intervals
             .iter()
             .map(|&interval| TimeInterval::try_from(interval))
@@ -294,4 +714,272 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn seek_matches_contains_time_for_sorted_queries() -> Result<(), TimeIntervalError> {
+        const INTERVALS: &[(Time, Time)] = &[(5, 10), (100, 200), (50, 60)];
+        let time_intervals = TimeIntervals::try_from(INTERVALS)?;
+        let mut cursor = Cursor::new();
+
+        // Feed the cursor a non-decreasing stream of timestamps and check it agrees with
+        // contains_time at every point, including the gaps between intervals.
+        for time in 0..=210 {
+            assert_eq!(
+                time_intervals.seek(time, &mut cursor),
+                time_intervals.contains_time(time),
+                "seek disagreed with contains_time at {time}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn seek_falls_back_when_time_regresses() -> Result<(), TimeIntervalError> {
+        const INTERVALS: &[(Time, Time)] = &[(5, 10), (50, 60), (100, 200)];
+        let time_intervals = TimeIntervals::try_from(INTERVALS)?;
+        let mut cursor = Cursor::new();
+
+        // Advance the cursor forward first.
+        assert!(time_intervals.seek(150, &mut cursor));
+
+        // Then query a time far below where the cursor is sitting; seek should still be correct
+        // even though the cursor has to fall back to a binary search.
+        assert!(time_intervals.seek(8, &mut cursor));
+        assert!(!time_intervals.seek(30, &mut cursor));
+        assert!(time_intervals.seek(55, &mut cursor));
+
+        Ok(())
+    }
+
+    #[test]
+    fn superset_and_subset() -> Result<(), TimeIntervalError> {
+        let wide = TimeIntervals::try_from([(0, 10), (20, 30)].as_slice())?;
+        let narrow = TimeIntervals::try_from([(2, 5), (22, 25)].as_slice())?;
+        let straddling = TimeIntervals::try_from([(8, 22)].as_slice())?;
+        let empty = TimeIntervals::from(Vec::new());
+
+        assert!(wide.is_superset(&narrow));
+        assert!(narrow.is_subset(&wide));
+        assert!(!narrow.is_superset(&wide));
+        assert!(!wide.is_subset(&narrow));
+
+        // An interval that straddles a gap in `wide` is not fully covered by it.
+        assert!(!wide.is_superset(&straddling));
+
+        // Everything is a superset of the empty set, and the empty set is a subset of everything.
+        assert!(wide.is_superset(&empty));
+        assert!(empty.is_subset(&wide));
+
+        // The empty set is only a superset of itself.
+        assert!(!empty.is_superset(&wide));
+        assert!(empty.is_superset(&empty));
+
+        Ok(())
+    }
+
+    #[test]
+    fn union() -> Result<(), TimeIntervalError> {
+        let a = TimeIntervals::try_from([(0, 10), (50, 60)].as_slice())?;
+        let b = TimeIntervals::try_from([(5, 20), (100, 200)].as_slice())?;
+
+        // [0, 10] and [5, 20] overlap and merge into [0, 20]; [50, 60] and [100, 200] don't.
+        assert_eq!(a.union(&b).intervals, [(0, 20), (50, 60), (100, 200)]);
+        assert_eq!(b.union(&a).intervals, [(0, 20), (50, 60), (100, 200)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection() -> Result<(), TimeIntervalError> {
+        let a = TimeIntervals::try_from([(0, 10), (50, 100)].as_slice())?;
+        let b = TimeIntervals::try_from([(5, 20), (60, 70), (90, 150)].as_slice())?;
+
+        assert_eq!(a.intersection(&b).intervals, [(5, 10), (60, 70), (90, 100)]);
+        assert_eq!(b.intersection(&a).intervals, [(5, 10), (60, 70), (90, 100)]);
+
+        let disjoint = TimeIntervals::try_from([(1000, 2000)].as_slice())?;
+        assert!(a.intersection(&disjoint).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference() -> Result<(), TimeIntervalError> {
+        let a = TimeIntervals::try_from([(0, 100)].as_slice())?;
+
+        // A cut strictly inside [0, 100] splits it into two pieces.
+        let cut_inside = TimeIntervals::try_from([(40, 60)].as_slice())?;
+        assert_eq!(a.difference(&cut_inside).intervals, [(0, 39), (61, 100)]);
+
+        // A cut overlapping the start just trims it.
+        let cut_start = TimeIntervals::try_from([(-10, 10)].as_slice())?;
+        assert_eq!(a.difference(&cut_start).intervals, [(11, 100)]);
+
+        // A cut that fully covers the interval removes it entirely.
+        let cut_all = TimeIntervals::try_from([(-10, 110)].as_slice())?;
+        assert!(a.difference(&cut_all).is_empty());
+
+        // Subtracting an empty set is a no-op.
+        let empty = TimeIntervals::from(Vec::new());
+        assert_eq!(a.difference(&empty).intervals, [(0, 100)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn gaps_iterator() -> Result<(), TimeIntervalError> {
+        const INTERVALS: &[(Time, Time)] = &[(5, 10), (100, 200), (50, 60)];
+        let time_intervals = TimeIntervals::try_from(INTERVALS)?;
+
+        let gaps: Vec<_> = time_intervals.gaps(TimeInterval::new(0, 250)?).collect();
+        assert_eq!(gaps, [(0, 4), (11, 49), (61, 99), (201, 250)]);
+
+        // A domain that starts inside a covered interval shouldn't yield a leading gap.
+        let gaps: Vec<_> = time_intervals.gaps(TimeInterval::new(8, 55)?).collect();
+        assert_eq!(gaps, [(11, 49)]);
+
+        // A domain fully inside a covered interval has no gaps at all.
+        let gaps: Vec<_> = time_intervals.gaps(TimeInterval::new(6, 9)?).collect();
+        assert!(gaps.is_empty());
+
+        // A domain entirely outside any interval is one big gap.
+        let gaps: Vec<_> = time_intervals.gaps(TimeInterval::new(300, 400)?).collect();
+        assert_eq!(gaps, [(300, 400)]);
+
+        // With no intervals at all, the whole domain is a gap.
+        let empty = TimeIntervals::from(Vec::new());
+        let gaps: Vec<_> = empty.gaps(TimeInterval::new(0, 10)?).collect();
+        assert_eq!(gaps, [(0, 10)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_interval_from_range() {
+        // Half-open `a..b` normalizes its exclusive end to our inclusive representation.
+        assert_eq!(TimeInterval::from_range(1..5).unwrap(), (1, 4));
+
+        // `a..=b` is already inclusive.
+        assert_eq!(TimeInterval::from_range(1..=5).unwrap(), (1, 5));
+
+        // Empty and degenerate ranges are rejected.
+        assert!(TimeInterval::from_range(5..5).is_err());
+        assert!(TimeInterval::from_range((Bound::Included(5), Bound::Included(1))).is_err());
+
+        // Unbounded sides are rejected, since TimeInterval is always finite and closed.
+        assert!(TimeInterval::from_range(..5).is_err());
+        assert!(TimeInterval::from_range(5..).is_err());
+        assert!(TimeInterval::<Time>::from_range(..).is_err());
+    }
+
+    #[test]
+    fn overlaps() -> Result<(), TimeIntervalError> {
+        const INTERVALS: &[(Time, Time)] = &[(5, 10), (50, 60), (100, 200)];
+        let time_intervals = TimeIntervals::try_from(INTERVALS)?;
+
+        // A half-open range that partially overlaps the first interval.
+        assert!(time_intervals.overlaps(8..20));
+
+        // A half-open range that lands entirely in a gap.
+        assert!(!time_intervals.overlaps(20..50));
+
+        // An inclusive range landing exactly on a boundary still overlaps.
+        assert!(time_intervals.overlaps(60..=61));
+
+        // A degenerate or unbounded range can never be covered.
+        assert!(!time_intervals.overlaps(5..5));
+        assert!(!time_intervals.overlaps(..));
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlap_count_and_coverage() -> Result<(), TimeIntervalError> {
+        const INTERVALS: &[(Time, Time)] = &[(5, 10), (50, 60), (100, 200)];
+        let time_intervals = TimeIntervals::try_from(INTERVALS)?;
+
+        // Query spans all three intervals, but only partially covers the first and last.
+        let query = TimeInterval::new(8, 150)?;
+        assert_eq!(time_intervals.overlap_count(query), 3);
+        // [8, 10] + [50, 60] + [100, 150] = 3 + 11 + 51
+        assert_eq!(time_intervals.coverage(query), 3 + 11 + 51);
+
+        // A query landing entirely in a gap overlaps nothing.
+        let gap_query = TimeInterval::new(20, 30)?;
+        assert_eq!(time_intervals.overlap_count(gap_query), 0);
+        assert_eq!(time_intervals.coverage(gap_query), 0);
+
+        // A query fully containing an interval counts it once and covers it completely.
+        let containing_query = TimeInterval::new(0, 15)?;
+        assert_eq!(time_intervals.overlap_count(containing_query), 1);
+        assert_eq!(time_intervals.coverage(containing_query), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn generic_over_u32() -> Result<(), TimeIntervalError> {
+        // The crate is no longer hardcoded to i64: callers can index by u32 (or any other Step
+        // type) without casting.
+        let intervals: TimeIntervals<u32> = TimeIntervals::try_from([(1u32, 2u32), (3u32, 6u32)].as_slice())?;
+
+        // (1, 2) and (3, 6) are adjacent under u32's Step impl (2 + 1 == 3), so they merge.
+        assert_eq!(intervals.intervals, [(1u32, 6u32)]);
+        assert!(intervals.contains_time(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn generic_over_u32_starting_at_zero() -> Result<(), TimeIntervalError> {
+        // An unsigned interval starting at 0 must not underflow while coalescing overlapping or
+        // adjacent intervals.
+        let intervals: TimeIntervals<u32> = TimeIntervals::try_from([(0u32, 3u32), (0u32, 10u32)].as_slice())?;
+
+        assert_eq!(intervals.intervals, [(0u32, 10u32)]);
+        assert!(intervals.contains_time(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn generic_over_u8_ending_at_max() -> Result<(), TimeIntervalError> {
+        // An interval ending at T::MAX must not overflow while coalescing overlapping intervals.
+        let intervals: TimeIntervals<u8> = TimeIntervals::try_from([(250u8, 255u8), (255u8, 255u8)].as_slice())?;
+
+        assert_eq!(intervals.intervals, [(250u8, 255u8)]);
+        assert!(intervals.contains_time(255));
+
+        Ok(())
+    }
+
+    #[test]
+    fn gaps_over_domain_ending_at_max() -> Result<(), TimeIntervalError> {
+        // An interval reaching T::MAX must not overflow while computing the trailing gap.
+        let intervals: TimeIntervals<u8> = TimeIntervals::try_from([(200u8, 255u8)].as_slice())?;
+
+        let gaps: Vec<_> = intervals.gaps(TimeInterval::new(0, 255)?).collect();
+        assert_eq!(gaps, [(0, 199)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_cut_ending_at_max() -> Result<(), TimeIntervalError> {
+        // A cut reaching T::MAX must not overflow while consuming the rest of the interval.
+        let a: TimeIntervals<u8> = TimeIntervals::try_from([(0u8, 255u8)].as_slice())?;
+        let cut: TimeIntervals<u8> = TimeIntervals::try_from([(100u8, 255u8)].as_slice())?;
+
+        assert_eq!(a.difference(&cut).intervals, [(0, 99)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_range_rejects_excluded_max() {
+        // An excluded start at T::MAX is unrepresentable (there's no value after it to start
+        // from), so this must be rejected with a TimeIntervalError, not overflow.
+        assert!(TimeInterval::from_range((Bound::Excluded(u8::MAX), Bound::Included(u8::MAX))).is_err());
+    }
 }